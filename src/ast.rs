@@ -0,0 +1,16 @@
+use crate::{scanner::Literal, token::Token};
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Literal(Literal),
+    Grouping(Box<Expr>),
+    Unary(Token, Box<Expr>),
+    Binary(Box<Expr>, Token, Box<Expr>),
+    Logical(Box<Expr>, Token, Box<Expr>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Stmt {
+    Expression(Expr),
+    Print(Expr),
+}
@@ -0,0 +1,218 @@
+use crate::{
+    ast::{Expr, Stmt},
+    scanner::Literal,
+    token::Token,
+    token_type::TokenType,
+};
+
+#[derive(Debug)]
+pub struct RuntimeError {
+    pub token: Token,
+    pub message: String,
+}
+
+/// A tree-walking evaluator for the AST produced by `Parser`.
+pub struct Interpreter;
+
+impl Interpreter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn interpret(&mut self, statements: &[Stmt]) -> Result<(), RuntimeError> {
+        for statement in statements {
+            self.execute(statement)?;
+        }
+        Ok(())
+    }
+
+    fn execute(&mut self, stmt: &Stmt) -> Result<(), RuntimeError> {
+        match stmt {
+            Stmt::Expression(expr) => {
+                self.evaluate(expr)?;
+            }
+            Stmt::Print(expr) => {
+                let value = self.evaluate(expr)?;
+                println!("{}", Self::stringify(&value));
+            }
+        }
+        Ok(())
+    }
+
+    fn evaluate(&mut self, expr: &Expr) -> Result<Literal, RuntimeError> {
+        match expr {
+            Expr::Literal(literal) => Ok(literal.clone()),
+            Expr::Grouping(inner) => self.evaluate(inner),
+            Expr::Unary(operator, right) => self.eval_unary(operator, right),
+            Expr::Binary(left, operator, right) => self.eval_binary(left, operator, right),
+            Expr::Logical(left, operator, right) => self.eval_logical(left, operator, right),
+        }
+    }
+
+    fn eval_unary(&mut self, operator: &Token, right: &Expr) -> Result<Literal, RuntimeError> {
+        let right = self.evaluate(right)?;
+
+        match operator.token_type {
+            TokenType::Minus => match right {
+                Literal::Number(n) => Ok(Literal::Number(-n)),
+                _ => Err(RuntimeError {
+                    token: operator.clone(),
+                    message: "Operand must be a number.".to_string(),
+                }),
+            },
+            TokenType::Bang => Ok(Literal::Bool(!Self::is_truthy(&right))),
+            _ => unreachable!("not a unary operator"),
+        }
+    }
+
+    fn eval_logical(
+        &mut self,
+        left: &Expr,
+        operator: &Token,
+        right: &Expr,
+    ) -> Result<Literal, RuntimeError> {
+        let left = self.evaluate(left)?;
+
+        match operator.token_type {
+            TokenType::Or if Self::is_truthy(&left) => Ok(left),
+            TokenType::And if !Self::is_truthy(&left) => Ok(left),
+            _ => self.evaluate(right),
+        }
+    }
+
+    fn eval_binary(
+        &mut self,
+        left: &Expr,
+        operator: &Token,
+        right: &Expr,
+    ) -> Result<Literal, RuntimeError> {
+        let left = self.evaluate(left)?;
+        let right = self.evaluate(right)?;
+
+        match operator.token_type {
+            TokenType::Minus => Self::numeric_op(operator, left, right, |a, b| a - b),
+            TokenType::Slash => Self::numeric_op(operator, left, right, |a, b| a / b),
+            TokenType::Star => Self::numeric_op(operator, left, right, |a, b| a * b),
+            TokenType::Plus => match (left, right) {
+                (Literal::Number(a), Literal::Number(b)) => Ok(Literal::Number(a + b)),
+                (Literal::String(a), Literal::String(b)) => Ok(Literal::String(a + &b)),
+                _ => Err(RuntimeError {
+                    token: operator.clone(),
+                    message: "Operands must be two numbers or two strings.".to_string(),
+                }),
+            },
+            TokenType::Greater => Self::compare_op(operator, left, right, |a, b| a > b),
+            TokenType::GreaterEqual => Self::compare_op(operator, left, right, |a, b| a >= b),
+            TokenType::Less => Self::compare_op(operator, left, right, |a, b| a < b),
+            TokenType::LessEqual => Self::compare_op(operator, left, right, |a, b| a <= b),
+            TokenType::EqualEqual => Ok(Literal::Bool(Self::is_equal(&left, &right))),
+            TokenType::BangEqual => Ok(Literal::Bool(!Self::is_equal(&left, &right))),
+            _ => unreachable!("not a binary operator"),
+        }
+    }
+
+    fn numeric_op(
+        operator: &Token,
+        left: Literal,
+        right: Literal,
+        op: impl Fn(f64, f64) -> f64,
+    ) -> Result<Literal, RuntimeError> {
+        match (left, right) {
+            (Literal::Number(a), Literal::Number(b)) => Ok(Literal::Number(op(a, b))),
+            _ => Err(RuntimeError {
+                token: operator.clone(),
+                message: "Operands must be numbers.".to_string(),
+            }),
+        }
+    }
+
+    fn compare_op(
+        operator: &Token,
+        left: Literal,
+        right: Literal,
+        op: impl Fn(f64, f64) -> bool,
+    ) -> Result<Literal, RuntimeError> {
+        match (left, right) {
+            (Literal::Number(a), Literal::Number(b)) => Ok(Literal::Bool(op(a, b))),
+            _ => Err(RuntimeError {
+                token: operator.clone(),
+                message: "Operands must be numbers.".to_string(),
+            }),
+        }
+    }
+
+    fn is_truthy(literal: &Literal) -> bool {
+        match literal {
+            Literal::None => false,
+            Literal::Bool(b) => *b,
+            _ => true,
+        }
+    }
+
+    fn is_equal(a: &Literal, b: &Literal) -> bool {
+        match (a, b) {
+            (Literal::None, Literal::None) => true,
+            (Literal::Number(a), Literal::Number(b)) => a == b,
+            (Literal::String(a), Literal::String(b)) => a == b,
+            (Literal::Bool(a), Literal::Bool(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    fn stringify(literal: &Literal) -> String {
+        match literal {
+            Literal::None => "nil".to_string(),
+            Literal::Bool(b) => b.to_string(),
+            Literal::Number(n) => n.to_string(),
+            Literal::String(s) => s.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parser::Parser, scanner::Scanner};
+
+    fn eval_expr(source: &str) -> Result<Literal, RuntimeError> {
+        let (tokens, _) = Scanner::new(source).scan_tokens();
+        let statements = Parser::new(tokens).parse().expect("parse error");
+        let mut interpreter = Interpreter::new();
+        match &statements[0] {
+            Stmt::Expression(expr) => interpreter.evaluate(expr),
+            other => panic!("expected an expression statement, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn multiplication_binds_tighter_than_addition() {
+        let value = eval_expr("1 + 2 * 3;").unwrap();
+        assert!(matches!(value, Literal::Number(n) if n == 7.0));
+    }
+
+    #[test]
+    fn subtraction_is_left_associative() {
+        // (1 - 2) - 3 == -4, not 1 - (2 - 3) == 2.
+        let value = eval_expr("1 - 2 - 3;").unwrap();
+        assert!(matches!(value, Literal::Number(n) if n == -4.0));
+    }
+
+    #[test]
+    fn plus_adds_numbers() {
+        let value = eval_expr("1 + 2;").unwrap();
+        assert!(matches!(value, Literal::Number(n) if n == 3.0));
+    }
+
+    #[test]
+    fn plus_concatenates_strings() {
+        let value = eval_expr("\"foo\" + \"bar\";").unwrap();
+        assert!(matches!(value, Literal::String(s) if s == "foobar"));
+    }
+
+    #[test]
+    fn plus_rejects_mixed_number_and_string() {
+        let err = eval_expr("1 + \"a\";").unwrap_err();
+        assert_eq!(err.message, "Operands must be two numbers or two strings.");
+        assert_eq!(err.token.line, 1);
+    }
+}
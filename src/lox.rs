@@ -1,19 +1,40 @@
 use std::io::BufRead;
 
-use crate::{scanner::Scanner, token_type::TokenType};
+use crate::{
+    interpreter::{Interpreter, RuntimeError},
+    parser::Parser,
+    scanner::Scanner,
+    token::Span,
+};
 
 pub struct Lox {
     had_error: bool,
+    had_runtime_error: bool,
 }
 
 impl Lox {
     pub fn new() -> Self {
-        Self { had_error: false }
+        Self {
+            had_error: false,
+            had_runtime_error: false,
+        }
     }
 
     pub fn run_file(mut self, path: &str) -> Result<(), std::io::Error> {
         let bytes = std::fs::read(path)?;
         self.run(&String::from_utf8(bytes).unwrap());
+
+        // Only decide the exit code once the whole file has been scanned,
+        // parsed and (if there were no errors) interpreted, so every scan
+        // error in the file gets reported before we exit. The parser stops
+        // at its first error, so at most one parse error is reported.
+        if self.had_error {
+            std::process::exit(65);
+        }
+        if self.had_runtime_error {
+            std::process::exit(70);
+        }
+
         Ok(())
     }
 
@@ -29,35 +50,78 @@ impl Lox {
             }
             self.run(&line);
             self.had_error = false;
+            self.had_runtime_error = false;
         }
         Ok(())
     }
 
     pub fn run(&mut self, source: &str) {
-        // Indicate an error in the exit code.
+        let mut scanner = Scanner::new(source);
+        let (tokens, scan_errors) = scanner.scan_tokens();
+
+        // Report every accumulated scan error at once, rather than bailing
+        // out after the first one.
+        for scan_error in &scan_errors {
+            self.error(
+                source,
+                scan_error.line,
+                scan_error.column,
+                scan_error.span,
+                scan_error.kind.message(),
+            );
+        }
         if self.had_error {
-            std::process::exit(65);
+            return;
         }
 
-        let mut scanner = Scanner::new(source);
-        let tokens = scanner.scan_tokens();
-
-        // For now, just print the tokens.
-        for token in tokens {
-            println!("{}", token.to_string());
-            if token.token_type == TokenType::Unknown {
-                self.error(token.line, "Unexpected character.");
+        let mut parser = Parser::new(tokens);
+        let statements = match parser.parse() {
+            Ok(statements) => statements,
+            Err(err) => {
+                self.error(source, err.line, err.column, err.span, &err.message);
+                return;
             }
+        };
+        if self.had_error {
+            return;
+        }
+
+        let mut interpreter = Interpreter::new();
+        if let Err(err) = interpreter.interpret(&statements) {
+            self.runtime_error(err);
         }
     }
 
-    fn error(&mut self, line: usize, message: &str) {
-        self.report(line, "", message);
+    fn error(&mut self, source: &str, line: usize, column: usize, span: Span, message: &str) {
+        self.report(source, line, column, span, "", message);
+    }
+
+    fn runtime_error(&mut self, error: RuntimeError) {
+        eprintln!("{}\n[line {}]", error.message, error.token.line);
+        self.had_runtime_error = true;
     }
 
-    fn report(&mut self, line: usize, where_: &str, message: &str) {
+    fn report(
+        &mut self,
+        source: &str,
+        line: usize,
+        column: usize,
+        span: Span,
+        where_: &str,
+        message: &str,
+    ) {
         eprintln!("[line {line}] Error{where_}: {message}");
 
+        if let Some(line_text) = source.lines().nth(line - 1) {
+            let underline_width = source[span.start..span.end].chars().count().max(1);
+            eprintln!("{line_text}");
+            eprintln!(
+                "{}{}",
+                " ".repeat(column.saturating_sub(1)),
+                "^".repeat(underline_width)
+            );
+        }
+
         self.had_error = true;
     }
 }
@@ -1,13 +1,25 @@
+use std::env;
+
 use crate::lox::Lox;
 
+mod ast;
+mod interpreter;
 mod lox;
+mod parser;
 mod scanner;
 mod token;
 mod token_type;
 
 fn main() -> Result<(), std::io::Error> {
-    println!("Hello, Lox!");
+    let args: Vec<String> = env::args().collect();
     let lox = Lox::new();
 
-    lox.run_prompt()
+    match args.len() {
+        1 => lox.run_prompt(),
+        2 => lox.run_file(&args[1]),
+        _ => {
+            eprintln!("Usage: rlox [script]");
+            std::process::exit(64);
+        }
+    }
 }
@@ -0,0 +1,179 @@
+use crate::{
+    ast::{Expr, Stmt},
+    scanner::Literal,
+    token::{Span, Token},
+    token_type::TokenType,
+};
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub span: Span,
+    pub message: String,
+}
+
+/// A Pratt/precedence-climbing parser: `parse_precedence` consumes a prefix
+/// expression, then keeps folding in infix operators whose binding power is
+/// at least `min_prec`, recursing with `prec + 1` to keep operators
+/// left-associative.
+pub struct Parser {
+    tokens: Vec<Token>,
+    current: usize,
+}
+
+impl Parser {
+    pub fn new(tokens: Vec<Token>) -> Self {
+        Self { tokens, current: 0 }
+    }
+
+    pub fn parse(&mut self) -> Result<Vec<Stmt>, ParseError> {
+        let mut statements = Vec::new();
+        while !self.is_at_end() {
+            statements.push(self.statement()?);
+        }
+        Ok(statements)
+    }
+
+    fn statement(&mut self) -> Result<Stmt, ParseError> {
+        if self.match_token(&[TokenType::Print]) {
+            return self.print_statement();
+        }
+        self.expression_statement()
+    }
+
+    fn print_statement(&mut self) -> Result<Stmt, ParseError> {
+        let value = self.expression()?;
+        self.consume(TokenType::Semicolon, "Expect ';' after value.")?;
+        Ok(Stmt::Print(value))
+    }
+
+    fn expression_statement(&mut self) -> Result<Stmt, ParseError> {
+        let expr = self.expression()?;
+        self.consume(TokenType::Semicolon, "Expect ';' after expression.")?;
+        Ok(Stmt::Expression(expr))
+    }
+
+    fn expression(&mut self) -> Result<Expr, ParseError> {
+        self.parse_precedence(1)
+    }
+
+    fn parse_precedence(&mut self, min_prec: u8) -> Result<Expr, ParseError> {
+        let mut expr = self.unary()?;
+
+        while let Some(prec) = Self::infix_precedence(self.peek().token_type) {
+            if prec < min_prec {
+                break;
+            }
+            let operator = self.advance().clone();
+            let right = self.parse_precedence(prec + 1)?;
+            expr = match operator.token_type {
+                TokenType::And | TokenType::Or => {
+                    Expr::Logical(Box::new(expr), operator, Box::new(right))
+                }
+                _ => Expr::Binary(Box::new(expr), operator, Box::new(right)),
+            };
+        }
+
+        Ok(expr)
+    }
+
+    fn infix_precedence(token_type: TokenType) -> Option<u8> {
+        match token_type {
+            TokenType::Or => Some(1),
+            TokenType::And => Some(2),
+            TokenType::EqualEqual | TokenType::BangEqual => Some(3),
+            TokenType::Less | TokenType::LessEqual | TokenType::Greater | TokenType::GreaterEqual => {
+                Some(4)
+            }
+            TokenType::Plus | TokenType::Minus => Some(5),
+            TokenType::Star | TokenType::Slash => Some(6),
+            _ => None,
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        if self.match_token(&[TokenType::Bang, TokenType::Minus]) {
+            let operator = self.previous().clone();
+            let right = self.parse_precedence(7)?;
+            return Ok(Expr::Unary(operator, Box::new(right)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, ParseError> {
+        if self.match_token(&[TokenType::False]) {
+            return Ok(Expr::Literal(Literal::Bool(false)));
+        }
+        if self.match_token(&[TokenType::True]) {
+            return Ok(Expr::Literal(Literal::Bool(true)));
+        }
+        if self.match_token(&[TokenType::Nil]) {
+            return Ok(Expr::Literal(Literal::None));
+        }
+        if self.match_token(&[TokenType::Number, TokenType::String]) {
+            return Ok(Expr::Literal(self.previous().literal.clone()));
+        }
+        if self.match_token(&[TokenType::LeftParen]) {
+            let expr = self.expression()?;
+            self.consume(TokenType::RightParen, "Expect ')' after expression.")?;
+            return Ok(Expr::Grouping(Box::new(expr)));
+        }
+
+        Err(ParseError {
+            line: self.peek().line,
+            column: self.peek().column,
+            span: self.peek().span,
+            message: "Expect expression.".to_string(),
+        })
+    }
+
+    fn match_token(&mut self, types: &[TokenType]) -> bool {
+        for token_type in types {
+            if self.check(*token_type) {
+                self.advance();
+                return true;
+            }
+        }
+        false
+    }
+
+    fn check(&self, token_type: TokenType) -> bool {
+        if self.is_at_end() {
+            return false;
+        }
+        self.peek().token_type == token_type
+    }
+
+    fn advance(&mut self) -> &Token {
+        if !self.is_at_end() {
+            self.current += 1;
+        }
+        self.previous()
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.peek().token_type == TokenType::Eof
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.current]
+    }
+
+    fn previous(&self) -> &Token {
+        &self.tokens[self.current - 1]
+    }
+
+    fn consume(&mut self, token_type: TokenType, message: &str) -> Result<&Token, ParseError> {
+        if self.check(token_type) {
+            return Ok(self.advance());
+        }
+
+        Err(ParseError {
+            line: self.peek().line,
+            column: self.peek().column,
+            span: self.peek().span,
+            message: message.to_string(),
+        })
+    }
+}
@@ -1,35 +1,99 @@
 use std::collections::HashMap;
 
-use crate::{token::Token, token_type::TokenType};
+use crate::{
+    token::{Span, Token},
+    token_type::TokenType,
+};
 
 #[derive(Debug, Clone)]
 pub enum Literal {
     String(String),
     Number(f64),
+    Bool(bool),
     None,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanErrorKind {
+    UnexpectedCharacter,
+    UnterminatedString,
+    UnterminatedBlockComment,
+    MissingDigitsAfterNumericPrefix,
+    InvalidDigitSeparator,
+    InvalidNumericLiteral,
+}
+
+impl ScanErrorKind {
+    /// The single source of truth for this error's diagnostic message —
+    /// callers never hand-write the text, so the two can't drift apart.
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::UnexpectedCharacter => "Unexpected character.",
+            Self::UnterminatedString => "Unterminated string.",
+            Self::UnterminatedBlockComment => "Unterminated block comment.",
+            Self::MissingDigitsAfterNumericPrefix => {
+                "Expect digits after numeric literal prefix."
+            }
+            Self::InvalidDigitSeparator => "Invalid digit separator in numeric literal.",
+            Self::InvalidNumericLiteral => "Invalid numeric literal.",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ScanError {
+    pub kind: ScanErrorKind,
+    pub line: usize,
+    pub column: usize,
+    pub span: Span,
+}
+
 pub struct Scanner {
-    source: String,
-    tokens: Vec<Token>,
+    chars: Vec<char>,
+    /// `byte_offsets[i]` is the byte offset of `chars[i]` in the original
+    /// source; `byte_offsets[chars.len()]` is the byte length of the source.
+    byte_offsets: Vec<usize>,
+    /// The token produced by the current `scan_token` call, if any — picked
+    /// up by `next_token` once a full lexeme has been scanned.
+    pending: Option<Token>,
+    errors: Vec<ScanError>,
     start: usize,
     current: usize,
     line: usize,
+    column: usize,
+    start_line: usize,
+    start_column: usize,
     keywords: HashMap<String, TokenType>,
 }
 
 impl Scanner {
     pub fn new(source: &str) -> Self {
+        let chars: Vec<char> = source.chars().collect();
+        let mut byte_offsets = Vec::with_capacity(chars.len() + 1);
+        let mut offset = 0;
+        for c in &chars {
+            byte_offsets.push(offset);
+            offset += c.len_utf8();
+        }
+        byte_offsets.push(offset);
+
         Self {
-            source: source.to_string(),
-            tokens: Vec::new(),
+            chars,
+            byte_offsets,
+            pending: None,
+            errors: Vec::new(),
             start: 0,
             current: 0,
             line: 1,
+            column: 1,
+            start_line: 1,
+            start_column: 1,
             keywords: {
                 let mut keywords = HashMap::new();
                 keywords.insert("and".to_string(), TokenType::And);
+                keywords.insert("break".to_string(), TokenType::Break);
                 keywords.insert("class".to_string(), TokenType::Class);
+                keywords.insert("continue".to_string(), TokenType::Continue);
                 keywords.insert("else".to_string(), TokenType::Else);
                 keywords.insert("false".to_string(), TokenType::False);
                 keywords.insert("for".to_string(), TokenType::For);
@@ -49,21 +113,56 @@ impl Scanner {
         }
     }
 
-    pub fn scan_tokens(&mut self) -> Vec<Token> {
-        while !self.is_at_end() {
+    /// Scans and returns the whole token stream in one pass, for the
+    /// tree-walk interpreter. Implemented in terms of `next_token`, so a
+    /// single-pass compiler can instead pull tokens one at a time without
+    /// materializing this `Vec`.
+    pub fn scan_tokens(&mut self) -> (Vec<Token>, Vec<ScanError>) {
+        let mut tokens = Vec::new();
+        loop {
+            let token = self.next_token();
+            let is_eof = token.token_type == TokenType::Eof;
+            tokens.push(token);
+            if is_eof {
+                break;
+            }
+        }
+
+        (tokens, self.errors.clone())
+    }
+
+    /// Scans exactly one token and returns it. Past the end of the source
+    /// this returns `Eof` forever, so callers can keep pulling without
+    /// checking `is_at_end` themselves.
+    pub fn next_token(&mut self) -> Token {
+        loop {
+            if self.is_at_end() {
+                let eof_offset = self.byte_offsets[self.current];
+                return Token::new(
+                    TokenType::Eof,
+                    "".to_string(),
+                    Literal::None,
+                    self.line,
+                    self.column,
+                    Span {
+                        start: eof_offset,
+                        end: eof_offset,
+                    },
+                );
+            }
+
             // We are at the beginning of the next lexeme.
             self.start = self.current;
+            self.start_line = self.line;
+            self.start_column = self.column;
             self.scan_token();
-        }
-
-        self.tokens.push(Token::new(
-            TokenType::Eof,
-            "".to_string(),
-            Literal::None,
-            self.line,
-        ));
 
-        self.tokens.clone()
+            if let Some(token) = self.pending.take() {
+                return token;
+            }
+            // Whitespace, comments and recoverable scan errors don't
+            // produce a token — keep scanning for the next lexeme.
+        }
     }
 
     fn scan_token(&mut self) {
@@ -117,6 +216,8 @@ impl Scanner {
                     while self.peek() != '\n' && !self.is_at_end() {
                         self.advance();
                     }
+                } else if self.match_char('*') {
+                    self.block_comment();
                 } else {
                     self.add_token(TokenType::Slash);
                 }
@@ -130,7 +231,7 @@ impl Scanner {
                 } else if self.is_alpha(c) {
                     self.identifier();
                 } else {
-                    self.add_token(TokenType::Unknown);
+                    self.error(ScanErrorKind::UnexpectedCharacter);
                 }
             }
         }
@@ -141,7 +242,7 @@ impl Scanner {
             self.advance();
         }
 
-        let text = self.source[self.start..self.current].to_string();
+        let text = self.lexeme();
         let token_type = self
             .keywords
             .get(&text)
@@ -152,7 +253,17 @@ impl Scanner {
     }
 
     fn number(&mut self) {
-        while self.is_digit(self.peek()) {
+        if self.chars[self.start] == '0' && matches!(self.peek(), 'x' | 'X' | 'b' | 'B' | 'o' | 'O') {
+            let base = match self.advance() {
+                'x' | 'X' => 16,
+                'b' | 'B' => 2,
+                'o' | 'O' => 8,
+                _ => unreachable!("prefix already matched above"),
+            };
+            return self.radix_number(base);
+        }
+
+        while self.is_digit(self.peek()) || self.peek() == '_' {
             self.advance();
         }
 
@@ -161,18 +272,98 @@ impl Scanner {
             // Consume the "."
             self.advance();
 
-            while self.is_digit(self.peek()) {
+            while self.is_digit(self.peek()) || self.peek() == '_' {
                 self.advance();
             }
         }
 
-        let value = self.source[self.start..self.current]
-            .parse::<f64>()
-            .unwrap();
+        let lexeme = self.lexeme();
+        if Self::has_invalid_separators(&lexeme) {
+            self.error(ScanErrorKind::InvalidDigitSeparator);
+            return;
+        }
+
+        let value = lexeme.replace('_', "").parse::<f64>().unwrap();
 
         self.add_token_with_literal(TokenType::Number, Literal::Number(value));
     }
 
+    /// Scans the digit run of a `0x`/`0b`/`0o` literal (the prefix has
+    /// already been consumed) and parses it with the given radix.
+    fn radix_number(&mut self, base: u32) {
+        let digits_start = self.current;
+        while self.is_in_base(self.peek(), base) || self.peek() == '_' {
+            self.advance();
+        }
+
+        let digits: String = self.chars[digits_start..self.current].iter().collect();
+
+        if digits.is_empty() {
+            self.error(ScanErrorKind::MissingDigitsAfterNumericPrefix);
+            return;
+        }
+        if Self::has_invalid_separators(&digits) {
+            self.error(ScanErrorKind::InvalidDigitSeparator);
+            return;
+        }
+
+        match i64::from_str_radix(&digits.replace('_', ""), base) {
+            Ok(value) => self.add_token_with_literal(TokenType::Number, Literal::Number(value as f64)),
+            Err(_) => self.error(ScanErrorKind::InvalidNumericLiteral),
+        }
+    }
+
+    /// A digit separator is only valid between two digits of the literal —
+    /// this rejects a leading/trailing/doubled `_` as well as one sitting
+    /// next to the decimal point (`1_.5`).
+    fn has_invalid_separators(lexeme: &str) -> bool {
+        let chars: Vec<char> = lexeme.chars().collect();
+        chars.iter().enumerate().any(|(i, &c)| {
+            if c != '_' {
+                return false;
+            }
+            let flanked = |neighbor: Option<char>| matches!(neighbor, Some(n) if n != '_' && n != '.');
+            !flanked(i.checked_sub(1).map(|j| chars[j])) || !flanked(chars.get(i + 1).copied())
+        })
+    }
+
+    fn is_in_base(&self, c: char, base: u32) -> bool {
+        match base {
+            2 => ('0'..='1').contains(&c),
+            8 => ('0'..='7').contains(&c),
+            16 => c.is_ascii_hexdigit(),
+            _ => self.is_digit(c),
+        }
+    }
+
+    /// Consumes a `/* ... */` comment, allowing nesting: each `/*` bumps the
+    /// depth and each `*/` drops it, so the comment only ends once depth
+    /// returns to zero. The opening `/*` has already been consumed.
+    fn block_comment(&mut self) {
+        let mut depth = 1;
+        while depth > 0 {
+            if self.is_at_end() {
+                self.error(ScanErrorKind::UnterminatedBlockComment);
+                return;
+            }
+
+            if self.peek() == '/' && self.peek_next() == '*' {
+                self.advance();
+                self.advance();
+                depth += 1;
+            } else if self.peek() == '*' && self.peek_next() == '/' {
+                self.advance();
+                self.advance();
+                depth -= 1;
+            } else {
+                if self.peek() == '\n' {
+                    self.line += 1;
+                }
+                self.advance();
+            }
+        }
+    }
+
     fn string(&mut self) {
         while self.peek() != '"' && !self.is_at_end() {
             if self.peek() == '\n' {
@@ -182,7 +373,7 @@ impl Scanner {
         }
 
         if self.is_at_end() {
-            self.add_token(TokenType::Unknown);
+            self.error(ScanErrorKind::UnterminatedString);
             return;
         }
 
@@ -190,7 +381,9 @@ impl Scanner {
         self.advance();
 
         // Trim the surrounding quotes.
-        let value = self.source[self.start + 1..self.current - 1].to_string();
+        let value = self.chars[self.start + 1..self.current - 1]
+            .iter()
+            .collect::<String>();
 
         self.add_token_with_literal(TokenType::String, Literal::String(value));
     }
@@ -199,10 +392,11 @@ impl Scanner {
         if self.is_at_end() {
             return false;
         }
-        if self.source.chars().nth(self.current).unwrap() != expected {
+        if self.chars[self.current] != expected {
             return false;
         }
         self.current += 1;
+        self.column += 1;
         true
     }
 
@@ -210,23 +404,23 @@ impl Scanner {
         if self.is_at_end() {
             return '\0';
         }
-        self.source.chars().nth(self.current).unwrap()
+        self.chars[self.current]
     }
 
     fn peek_next(&self) -> char {
-        if self.current + 1 >= self.source.len() {
+        if self.current + 1 >= self.chars.len() {
             return '\0';
         }
 
-        self.source.chars().nth(self.current + 1).unwrap()
+        self.chars[self.current + 1]
     }
 
     fn is_alpha(&self, c: char) -> bool {
-        (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || c == '_'
+        c.is_ascii_alphabetic() || c == '_'
     }
 
     fn is_digit(&self, c: char) -> bool {
-        c >= '0' && c <= '9'
+        c.is_ascii_digit()
     }
 
     fn is_alpha_numeric(&self, c: char) -> bool {
@@ -234,22 +428,152 @@ impl Scanner {
     }
 
     fn is_at_end(&self) -> bool {
-        self.current >= self.source.len()
+        self.current >= self.chars.len()
     }
 
     fn advance(&mut self) -> char {
-        let c = self.source.chars().nth(self.current).unwrap();
+        let c = self.chars[self.current];
         self.current += 1;
+        if c == '\n' {
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
         c
     }
 
+    fn lexeme(&self) -> String {
+        self.chars[self.start..self.current].iter().collect()
+    }
+
     fn add_token(&mut self, token_type: TokenType) {
         self.add_token_with_literal(token_type, Literal::None);
     }
 
+    fn error(&mut self, kind: ScanErrorKind) {
+        let span = Span {
+            start: self.byte_offsets[self.start],
+            end: self.byte_offsets[self.current],
+        };
+        // Report at the lexeme's start, not wherever scanning stopped — an
+        // unterminated string or block comment can swallow newlines before
+        // the error fires, and `self.line` would then point past the end
+        // of the lexeme instead of at it.
+        self.errors.push(ScanError {
+            kind,
+            line: self.start_line,
+            column: self.start_column,
+            span,
+        });
+    }
+
     fn add_token_with_literal(&mut self, token_type: TokenType, literal: Literal) {
-        let text = self.source[self.start..self.current].to_string();
-        self.tokens
-            .push(Token::new(token_type, text, literal, self.line));
+        let text = self.lexeme();
+        let span = Span {
+            start: self.byte_offsets[self.start],
+            end: self.byte_offsets[self.current],
+        };
+        self.pending = Some(Token::new(
+            token_type,
+            text,
+            literal,
+            self.line,
+            self.start_column,
+            span,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_literal() {
+        let (tokens, errors) = Scanner::new("0xFF;").scan_tokens();
+        assert!(errors.is_empty());
+        assert!(matches!(tokens[0].literal, Literal::Number(n) if n == 255.0));
+    }
+
+    #[test]
+    fn binary_literal() {
+        let (tokens, errors) = Scanner::new("0b101;").scan_tokens();
+        assert!(errors.is_empty());
+        assert!(matches!(tokens[0].literal, Literal::Number(n) if n == 5.0));
+    }
+
+    #[test]
+    fn octal_literal() {
+        let (tokens, errors) = Scanner::new("0o17;").scan_tokens();
+        assert!(errors.is_empty());
+        assert!(matches!(tokens[0].literal, Literal::Number(n) if n == 15.0));
+    }
+
+    #[test]
+    fn decimal_literal_with_digit_separators() {
+        let (tokens, errors) = Scanner::new("1_000_000;").scan_tokens();
+        assert!(errors.is_empty());
+        assert!(matches!(tokens[0].literal, Literal::Number(n) if n == 1_000_000.0));
+    }
+
+    #[test]
+    fn decimal_fractional_literal_still_works() {
+        let (tokens, errors) = Scanner::new("2.5;").scan_tokens();
+        assert!(errors.is_empty());
+        assert!(matches!(tokens[0].literal, Literal::Number(n) if n == 2.5));
+    }
+
+    #[test]
+    fn prefix_without_digits_is_rejected() {
+        let (_, errors) = Scanner::new("0x;").scan_tokens();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ScanErrorKind::MissingDigitsAfterNumericPrefix);
+    }
+
+    #[test]
+    fn doubled_underscore_is_rejected() {
+        let (_, errors) = Scanner::new("1__0;").scan_tokens();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ScanErrorKind::InvalidDigitSeparator);
+    }
+
+    #[test]
+    fn trailing_underscore_is_rejected() {
+        let (_, errors) = Scanner::new("100_;").scan_tokens();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ScanErrorKind::InvalidDigitSeparator);
+    }
+
+    #[test]
+    fn underscore_next_to_decimal_point_is_rejected() {
+        let (_, errors) = Scanner::new("1_.5;").scan_tokens();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ScanErrorKind::InvalidDigitSeparator);
+    }
+
+    #[test]
+    fn nested_block_comments_are_skipped() {
+        let (tokens, errors) =
+            Scanner::new("/* outer /* inner */ still outer */ 1;").scan_tokens();
+        assert!(errors.is_empty());
+        assert!(matches!(tokens[0].literal, Literal::Number(n) if n == 1.0));
+    }
+
+    #[test]
+    fn unterminated_block_comment_is_rejected() {
+        let (_, errors) = Scanner::new("/* outer /* inner */ still outer").scan_tokens();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ScanErrorKind::UnterminatedBlockComment);
+    }
+
+    #[test]
+    fn unterminated_multiline_string_reports_opening_line() {
+        // The string swallows two newlines before scanning hits EOF; the
+        // error should still point at the opening quote on line 1, not the
+        // line scanning stopped on.
+        let (_, errors) = Scanner::new("\"abc\ndef\nghi").scan_tokens();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ScanErrorKind::UnterminatedString);
+        assert_eq!(errors[0].line, 1);
     }
 }
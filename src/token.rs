@@ -0,0 +1,48 @@
+use std::fmt;
+
+use crate::{scanner::Literal, token_type::TokenType};
+
+/// A byte-offset range into the original source, for caret-style
+/// diagnostics and future tooling (LSP ranges, etc.) that need more than a
+/// line number.
+#[derive(Debug, Clone, Copy)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub token_type: TokenType,
+    pub lexeme: String,
+    pub literal: Literal,
+    pub line: usize,
+    pub column: usize,
+    pub span: Span,
+}
+
+impl Token {
+    pub fn new(
+        token_type: TokenType,
+        lexeme: String,
+        literal: Literal,
+        line: usize,
+        column: usize,
+        span: Span,
+    ) -> Self {
+        Self {
+            token_type,
+            lexeme,
+            literal,
+            line,
+            column,
+            span,
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} {} {:?}", self.token_type, self.lexeme, self.literal)
+    }
+}